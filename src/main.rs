@@ -2,10 +2,14 @@
 
 use std::ops::{Not};
 use anyhow::{Result, Ok, Context};
+use std::env;
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
 use std::vec;
+use lru::LruCache;
 use rand::Rng;
 use sdl2::pixels::Color;
-use sdl2::event::Event;
+use sdl2::event::{Event, WindowEvent};
 use sdl2::keyboard::Keycode;
 use sdl2::EventPump;
 use sdl2::rect::{Point, Rect};
@@ -13,6 +17,15 @@ use sdl2::render::{Texture, TextureCreator, WindowCanvas};
 use sdl2::ttf::Font;
 use sdl2::ttf::Sdl2TtfContext;
 use sdl2::video::WindowContext;
+use sdl2::controller::{Axis, Button, GameController};
+use sdl2::GameControllerSubsystem;
+use sdl2::image::{self, InitFlag as ImageInitFlag, LoadTexture, Sdl2ImageContext};
+#[cfg(feature = "audio")]
+use sdl2::mixer;
+#[cfg(feature = "audio")]
+use sdl2::mixer::{Chunk, Sdl2MixerContext};
+#[cfg(feature = "audio")]
+use sdl2::AudioSubsystem;
 
 /// [`Sign`] to represent the players.
 #[derive(Copy, Clone, PartialEq)]
@@ -46,7 +59,7 @@ impl Not for Sign {
 struct Cell(Option<Sign>);
 
 impl Cell {
-    /// # Returns 
+    /// # Returns
     ///
     /// true if the [`Cell`] is [`None`].
     fn is_empty(&self) -> bool {
@@ -84,20 +97,26 @@ impl Field {
         self.0[0].len()
     }
 
-    /// Draw the [`Field`] to the given `canvas`.
-    fn draw(&self, game_state: &mut GameState, canvas: &mut WindowCanvas) -> Result<()> {
+    /// Draw the [`Field`] to the given `canvas`. `now` is the current frame's timestamp, used to
+    /// animate signs that were just placed; see [`PLACEMENT_ANIMATION_DURATION`].
+    fn draw(&self, game_state: &mut GameState, canvas: &mut WindowCanvas, now: Instant) -> Result<()> {
         let window_size = canvas.window().size();
-        let cell_size = window_size.0 / 5;
+        // `+ 2` leaves the same margin around the field as the original hardcoded `/ 5` did for
+        // the default 3x3 board, but now scales down as the board grows.
+        let cell_size = window_size.0 / (self.row_count() as u32 + 2);
         let padding = cell_size / 4;
         let field_size = cell_size * self.row_count() as u32 + padding * (self.row_count() as u32 - 1);
         let remaining_window_width = (window_size.0 - field_size) as i32;
         let remaining_window_height = (window_size.0 - field_size) as i32;
-        let texture_creator = canvas.texture_creator();
 
         // Fill field_rects with "empty" rects (not a nice solution but it works
         if game_state.field_rects.is_empty() {
             game_state.field_rects = vec![vec![Rect::new(0, 0, 0, 0); self.column_count()]; self.row_count()];
         }
+        // Same as field_rects: fill placement_times with "nothing placed yet" entries.
+        if game_state.placement_times.is_empty() {
+            game_state.placement_times = vec![vec![None; self.column_count()]; self.row_count()];
+        }
 
         for (row_idx, row) in self.0.iter().enumerate() {
             for (col_idx, cell) in row.iter().enumerate() {
@@ -119,13 +138,37 @@ impl Field {
                 canvas.set_draw_color(BACKGROUND_COLOR);
 
                 // Draw Sign
-                let sign_text = match cell.0 {
-                    Some(sign) => sign.into(),
-                    None => " ",
-                };
-                let sign_texture = get_text_texture(sign_text, &game_state.font, &texture_creator).context("Creating texture for player Sign.")?;
-                let target = Rect::new(cell_x_pos, cell_y_pos, cell_size, cell_size);
-                canvas.copy(&sign_texture, None, Some(target)).expect("Displaying texture for player Sign."); //TODO: Really do not want to use expect here
+                if let Some(sign) = cell.0 {
+                    let sprite = match sign {
+                        Sign::X => &game_state.sprites.x,
+                        Sign::O => &game_state.sprites.o,
+                    };
+
+                    // Scale the sprite up from nothing and spin it in over PLACEMENT_ANIMATION_DURATION.
+                    let progress = match game_state.placement_times[row_idx][col_idx] {
+                        Some(placed_at) => (now.saturating_duration_since(placed_at).as_secs_f64()
+                            / PLACEMENT_ANIMATION_DURATION.as_secs_f64()).min(1.0),
+                        None => 1.0,
+                    };
+                    let scale = progress as f32;
+                    let angle = (1.0 - progress) * 360.0;
+                    let sprite_size = (cell_size as f32 * scale) as u32;
+                    let sprite_rect = Rect::new(
+                        cell_x_pos + (cell_size as i32 - sprite_size as i32) / 2,
+                        cell_y_pos + (cell_size as i32 - sprite_size as i32) / 2,
+                        sprite_size,
+                        sprite_size,
+                    );
+                    canvas.copy_ex(sprite, None, Some(sprite_rect), angle, None, false, false)
+                        .expect("Displaying sprite for player Sign."); //TODO: Really do not want to use expect here
+                }
+
+                // Highlight the cell currently selected by the gamepad/keyboard cursor
+                if (row_idx, col_idx) == (game_state.selected_row, game_state.selected_col) {
+                    canvas.set_draw_color(SELECTION_COLOR);
+                    canvas.draw_rect(cell_rect).expect("Possibly graphic driver failure!");
+                    canvas.set_draw_color(BACKGROUND_COLOR);
+                }
             }
         }
 
@@ -135,32 +178,125 @@ impl Field {
 
 struct GameState<'a> {
     font: Font<'a, 'a>,
+    texture_creator: &'a TextureCreator<WindowContext>,
+    /// Cache of rendered text textures keyed by their text and color, so we don't re-render
+    /// and re-upload the same end-of-game text to the GPU every single frame.
+    texture_cache: LruCache<(String, Color), Texture<'a>>,
+    #[cfg(feature = "audio")]
+    sounds: Sounds,
+    sprites: Sprites<'a>,
     field: Field,
     /// The actual [`Rect`]s on screen
     field_rects: Vec<Vec<Rect>>,
+    /// When each [`Cell`] was last placed into, if ever; drives the placement animation in
+    /// [`Field::draw`]. Lazily filled in alongside `field_rects`.
+    placement_times: Vec<Vec<Option<Instant>>>,
+    /// Side length of the (square) [`Field`].
+    board_size: usize,
+    /// Length of a run of the same [`Sign`] required to win.
+    win_len: usize,
+    /// The [`Sign`] played by the minimax AI, if single-player mode is toggled on.
+    ai_sign: Option<Sign>,
     current_player: Sign,
     has_won: bool,
+    has_drawn: bool,
+    /// The cell currently highlighted by the gamepad/keyboard cursor.
+    selected_row: usize,
+    selected_col: usize,
+    /// Last edge-triggered direction of the left stick's X/Y axes, so holding the stick past
+    /// the deadzone moves the cursor once instead of every single axis event.
+    controller_stick_prev: (i8, i8),
+}
+
+/// Sound effects played at the obvious game events, loaded once and held for the game's lifetime.
+#[cfg(feature = "audio")]
+struct Sounds {
+    click: Chunk,
+    win: Chunk,
+    draw: Chunk,
+}
+
+#[cfg(feature = "audio")]
+impl Sounds {
+    /// Loads all sound effects from `assets/`.
+    fn load() -> Result<Self> {
+        Ok(Self {
+            click: Chunk::from_file("assets/click.wav").map_err(anyhow::Error::msg).context("Loading click sound")?,
+            win: Chunk::from_file("assets/win.wav").map_err(anyhow::Error::msg).context("Loading win sound")?,
+            draw: Chunk::from_file("assets/draw.wav").map_err(anyhow::Error::msg).context("Loading draw sound")?,
+        })
+    }
+}
+
+/// The image sprites drawn for each [`Sign`], loaded once and held for the game's lifetime.
+struct Sprites<'a> {
+    x: Texture<'a>,
+    o: Texture<'a>,
+}
+
+impl<'a> Sprites<'a> {
+    /// Loads both sprites from `assets/`.
+    fn load(texture_creator: &'a TextureCreator<WindowContext>) -> Result<Self> {
+        Ok(Self {
+            x: texture_creator.load_texture("assets/x.png").map_err(anyhow::Error::msg).context("Loading X sprite")?,
+            o: texture_creator.load_texture("assets/o.png").map_err(anyhow::Error::msg).context("Loading O sprite")?,
+        })
+    }
 }
 
 const BACKGROUND_COLOR: Color = Color::RGB(69, 69, 69);
-const FIELD_SIZE: usize = 3;
+const TEXT_COLOR: Color = Color::RGB(0, 255, 0);
+const SELECTION_COLOR: Color = Color::RGB(255, 255, 0);
+/// Board size and win length used when no CLI arguments are given.
+const DEFAULT_BOARD_SIZE: usize = 3;
+const DEFAULT_WIN_LEN: usize = 3;
+/// Largest `board_size` the minimax AI ([`best_move`]) may be toggled on for. `minimax` searches
+/// the full, unpruned game tree, which is only tractable for the default 3x3 board; anything
+/// bigger would block the event loop for an unreasonable amount of time.
+const MAX_AI_BOARD_SIZE: usize = 3;
+/// Capacity of [`GameState::texture_cache`]; a handful of end-of-game texts is all this game ever
+/// renders with the font.
+const TEXTURE_CACHE_CAPACITY: usize = 32;
+/// Left-stick axis values within this distance of `0` are ignored, so small stick drift doesn't
+/// move the cursor.
+const CONTROLLER_AXIS_DEADZONE: i16 = 10_000;
+/// How long a just-placed [`Sign`] takes to scale and spin into its cell; see [`Field::draw`].
+const PLACEMENT_ANIMATION_DURATION: Duration = Duration::from_millis(150);
 
 fn main() {
-    let (mut canvas, mut event_pump, ttf_context) = setup_sdl();
+    let (board_size, win_len) = parse_board_config();
+    let mut sdl = setup_sdl();
+    let texture_creator = sdl.canvas.texture_creator();
 
     // Setup GameState
-    let font = ttf_context.load_font("assets/ComicSansMS3.ttf", 69).expect("Loading font");
+    let font = sdl.ttf_context.load_font("assets/ComicSansMS3.ttf", 69).expect("Loading font");
+    #[cfg(feature = "audio")]
+    let sounds = Sounds::load().expect("Loading sound effects");
+    let sprites = Sprites::load(&texture_creator).expect("Loading sprites");
     let mut game_state = GameState {
         font,
-        field: Field::empty(FIELD_SIZE),
+        texture_creator: &texture_creator,
+        texture_cache: LruCache::new(NonZeroUsize::new(TEXTURE_CACHE_CAPACITY).expect("Non-zero cache capacity")),
+        #[cfg(feature = "audio")]
+        sounds,
+        sprites,
+        field: Field::empty(board_size),
         field_rects: vec![],
+        placement_times: vec![],
+        board_size,
+        win_len,
+        ai_sign: None,
         current_player: get_random_player(),
         has_won: false,
+        has_drawn: false,
+        selected_row: 0,
+        selected_col: 0,
+        controller_stick_prev: (0, 0),
     };
 
     // Game Loop
     'running: loop {
-        for event in event_pump.poll_iter() {
+        for event in sdl.event_pump.poll_iter() {
             match event {
                 Event::Quit { .. } |
                 Event::KeyDown { keycode: Some(Keycode::Escape), .. } => {
@@ -168,13 +304,37 @@ fn main() {
                 }
                 Event::KeyDown { keycode: Some(Keycode::R), .. } => {
                     game_state = reset_game(game_state);
+                    maybe_play_ai_turn(&mut game_state);
+                }
+                Event::KeyDown { keycode: Some(Keycode::M), .. } if game_state.ai_sign.is_some() || game_state.board_size <= MAX_AI_BOARD_SIZE => {
+                    game_state.ai_sign = match game_state.ai_sign {
+                        Some(_) => None,
+                        None => Some(!game_state.current_player),
+                    };
+                    maybe_play_ai_turn(&mut game_state);
+                }
+                Event::Window { win_event: WindowEvent::Resized(..) | WindowEvent::SizeChanged(..), .. } => {
+                    game_state.texture_cache.clear();
                 }
                 Event::MouseButtonDown { y, x, .. } if !game_state.has_won => on_mouse_clicked(x, y, &mut game_state),
+                Event::ControllerButtonDown { button: Button::Start, .. } => {
+                    game_state = reset_game(game_state);
+                    maybe_play_ai_turn(&mut game_state);
+                }
+                Event::ControllerButtonDown { button, .. } if !game_state.has_won => {
+                    on_controller_button_down(button, &mut game_state);
+                }
+                Event::ControllerAxisMotion { axis, value, .. } if !game_state.has_won => {
+                    on_controller_axis_motion(axis, value, &mut game_state);
+                }
+                Event::KeyDown { keycode: Some(keycode), .. } if !game_state.has_won => {
+                    on_keyboard_input(keycode, &mut game_state);
+                }
                 _ => {}
             }
         }
 
-        update(&mut canvas, &mut game_state).expect("Failed updating the game");
+        update(&mut sdl.canvas, &mut game_state).expect("Failed updating the game");
     }
 }
 
@@ -182,22 +342,32 @@ fn main() {
 fn update(canvas: &mut WindowCanvas, game_state: &mut GameState) -> Result<()> {
     canvas.clear();
     let field = game_state.field.clone();
-    let texture_creator = canvas.texture_creator();
     let window_size = canvas.window().size();
+    let now = Instant::now();
 
-    field.draw(game_state, canvas).context("Drawing game Field")?;
+    field.draw(game_state, canvas, now).context("Drawing game Field")?;
 
     // Check for win or draw
-    if check_win(&game_state.field, &!game_state.current_player) {
+    if check_win(&game_state.field, &!game_state.current_player, game_state.win_len) {
+        if !game_state.has_won {
+            #[cfg(feature = "audio")]
+            mixer::Channel::all().play(&game_state.sounds.win, 0).ok();
+        }
         game_state.has_won = true;
 
         let mut text = "Player ".to_owned();
         text.push_str((!game_state.current_player).into());
         text.push_str(" has won!");
 
-        draw_end_text(&*text, (window_size.0 / 2, window_size.1 / 8), &game_state.font, &texture_creator, canvas)?;
+        draw_end_text(&*text, (window_size.0 / 2, window_size.1 / 8), canvas, game_state)?;
     } else if check_draw(&game_state.field) {
-        draw_end_text("It is a tie!", (window_size.0 / 4, window_size.1 / 8), &game_state.font, &texture_creator, canvas)?;
+        if !game_state.has_drawn {
+            #[cfg(feature = "audio")]
+            mixer::Channel::all().play(&game_state.sounds.draw, 0).ok();
+        }
+        game_state.has_drawn = true;
+
+        draw_end_text("It is a tie!", (window_size.0 / 4, window_size.1 / 8), canvas, game_state)?;
     }
 
     canvas.present();
@@ -205,11 +375,37 @@ fn update(canvas: &mut WindowCanvas, game_state: &mut GameState) -> Result<()> {
     Ok(())
 }
 
+/// Handles that only exist to keep the mixer subsystem alive for the process lifetime; a unit
+/// type when the `audio` feature is disabled so headless builds don't pay for SDL2_mixer at all.
+#[cfg(feature = "audio")]
+type AudioHandles = (AudioSubsystem, Sdl2MixerContext);
+#[cfg(not(feature = "audio"))]
+type AudioHandles = ();
+
+/// Bundles the long-lived SDL2 handles obtained once at startup.
+struct SdlContext {
+    canvas: WindowCanvas,
+    event_pump: EventPump,
+    ttf_context: Sdl2TtfContext,
+    /// Only kept alive so the opened [`GameController`] keeps producing events; not read again.
+    _controller_subsystem: GameControllerSubsystem,
+    /// Kept open for the game's lifetime so its buttons/axes keep producing events; `None` if
+    /// no gamepad was connected at startup.
+    _controller: Option<GameController>,
+    /// Only used to keep the audio device alive; see [`AudioHandles`].
+    #[cfg(feature = "audio")]
+    _audio_handles: AudioHandles,
+    /// Only kept alive so PNG loading via `sdl2::image` keeps working.
+    _image_context: Sdl2ImageContext,
+}
+
 /// Setup everything that has to do with SDL2.
-fn setup_sdl() -> (WindowCanvas, EventPump, Sdl2TtfContext) {
+fn setup_sdl() -> SdlContext {
     let sdl_context = sdl2::init().expect("Initializing SDL2");
     let video_subsystem = sdl_context.video().expect("Initializing Video Subsystem");
     let ttf_context = sdl2::ttf::init().expect("Initializing TTF Context");
+    let image_context = image::init(ImageInitFlag::PNG).expect("Initializing SDL2_image");
+    let controller_subsystem = sdl_context.game_controller().expect("Initializing Game Controller Subsystem");
 
     let window = video_subsystem.window("tic_tac_toe_rs", 600, 600)
         .position_centered()
@@ -224,48 +420,94 @@ fn setup_sdl() -> (WindowCanvas, EventPump, Sdl2TtfContext) {
     canvas.present();
     let event_pump = sdl_context.event_pump().expect("Getting Event Dump");
 
-    (canvas, event_pump, ttf_context)
+    let controller = (0..controller_subsystem.num_joysticks().unwrap_or(0))
+        .find(|&id| controller_subsystem.is_game_controller(id))
+        .and_then(|id| controller_subsystem.open(id).ok());
+
+    #[cfg(feature = "audio")]
+    let _audio_handles: AudioHandles = {
+        let audio_subsystem = sdl_context.audio().expect("Initializing Audio Subsystem");
+        mixer::open_audio(44_100, mixer::AUDIO_S16LSB, mixer::DEFAULT_CHANNELS, 1_024).expect("Opening audio device");
+        let mixer_context = mixer::init(mixer::InitFlag::empty()).expect("Initializing SDL2_mixer");
+        (audio_subsystem, mixer_context)
+    };
+    #[cfg(not(feature = "audio"))]
+    let _audio_handles: AudioHandles = ();
+
+    SdlContext {
+        canvas,
+        event_pump,
+        ttf_context,
+        _controller_subsystem: controller_subsystem,
+        _controller: controller,
+        #[cfg(feature = "audio")]
+        _audio_handles,
+        _image_context: image_context,
+    }
 }
 
 /// Resets the [`GameState`].
 fn reset_game(game_state: GameState) -> GameState {
     GameState {
         font: game_state.font,
-        field: Field::empty(FIELD_SIZE),
+        texture_creator: game_state.texture_creator,
+        texture_cache: game_state.texture_cache,
+        #[cfg(feature = "audio")]
+        sounds: game_state.sounds,
+        sprites: game_state.sprites,
+        field: Field::empty(game_state.board_size),
         field_rects: vec![],
+        placement_times: vec![],
+        board_size: game_state.board_size,
+        win_len: game_state.win_len,
+        ai_sign: game_state.ai_sign,
         current_player: get_random_player(),
         has_won: false,
+        has_drawn: false,
+        selected_row: 0,
+        selected_col: 0,
+        controller_stick_prev: (0, 0),
     }
 }
 
-/// Checks if the game has ended in a win for the given `player`.
-fn check_win(field: &Field, player: &Sign) -> bool {
-    let mut field = field.clone();
-
-    // Rows
-    if check_win_rows(&field, player) {
-        return true;
+/// The four directions a run of [`Sign`]s can extend in: right, down, down-right, up-right.
+/// Together with their opposites (walked by starting the run from the other end) these cover
+/// every row, column and diagonal.
+const WIN_DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (-1, 1)];
+
+/// Checks if the game has ended in a win for the given `player`, i.e. whether the `field`
+/// contains a run of `win_len` of the `player`'s [`Sign`] in a row, column or diagonal.
+fn check_win(field: &Field, player: &Sign, win_len: usize) -> bool {
+    for row in 0..field.row_count() {
+        for col in 0..field.column_count() {
+            for (row_step, col_step) in WIN_DIRECTIONS {
+                if check_run(field, player, win_len, row, col, row_step, col_step) {
+                    return true;
+                }
+            }
+        }
     }
 
-    // Diagonals
-    if let Some(middle_sign) = field.0[1][1].0 {
-        // Left-Top to Right-Bottom
-        if &middle_sign == player && field.0[0][0] == field.0[1][1] && field.0[1][1] == field.0[2][2] {
-            return true;
-        }
+    false
+}
+
+/// Checks if a run of `win_len` of the `player`'s [`Sign`] starts at `(row, col)` and extends in
+/// the `(row_step, col_step)` direction, staying in bounds.
+fn check_run(field: &Field, player: &Sign, win_len: usize, row: usize, col: usize, row_step: isize, col_step: isize) -> bool {
+    for step in 0..win_len as isize {
+        let run_row = row as isize + row_step * step;
+        let run_col = col as isize + col_step * step;
 
-        if &middle_sign == player && field.0[0][2] == field.0[1][1] && field.0[1][1] == field.0[2][0] {
-            return true;
+        if run_row < 0 || run_col < 0 || run_row as usize >= field.row_count() || run_col as usize >= field.column_count() {
+            return false;
         }
-    }
 
-    // Cols
-    field = rotate_field_90deg(&field);
-    if check_win_rows(&field, player) {
-        return true;
+        if field.0[run_row as usize][run_col as usize].0 != Some(*player) {
+            return false;
+        }
     }
 
-    false
+    true
 }
 
 /// Checks if the game has ended in a draw.
@@ -281,19 +523,8 @@ fn check_draw(field: &Field) -> bool {
     true
 }
 
-/// Checks if the `field` contains a row with three of the same [`Sign`]s.
-fn check_win_rows(field: &Field, player: &Sign) -> bool {
-    if field.0
-        .windows(FIELD_SIZE)
-        .any(|row| row.contains(&vec![Cell(Some(*player)); FIELD_SIZE])) {
-        return true;
-    }
-
-    false
-}
-
 /// # Return
-/// 
+///
 /// a random [`Sign`] to use as the player.
 fn get_random_player() -> Sign {
     let player = rand::thread_rng().gen_range(0_u32..=1_u32);
@@ -304,42 +535,43 @@ fn get_random_player() -> Sign {
     }
 }
 
-/// Rotates the field by 90 degrees clockwise.
-///
-/// (at this time I am not so smart that I could do this so I "borrowed" it from:
-/// [qiwei9743 on Leetcode](https://leetcode.com/problems/rotate-image/solutions/435653/rust-with-std::mem::swap-in-2D-vector))
-fn rotate_field_90deg(field: &Field) -> Field {
-    let mut field = field.clone();
+/// Reads `board_size` and `win_len` from the command line (`tic_tac_toe_rs [board_size] [win_len]`),
+/// e.g. `tic_tac_toe_rs 5 4` for a 5x5 board with 4-in-a-row. Falls back to
+/// [`DEFAULT_BOARD_SIZE`]/[`DEFAULT_WIN_LEN`] on missing or invalid arguments; the fallback
+/// `win_len` is capped at `board_size`, and a `win_len` larger than `board_size` is invalid.
+fn parse_board_config() -> (usize, usize) {
+    let mut args = env::args().skip(1);
 
-    field.0.reverse();
-    for i in 1..field.0.len() {
-        let (left, right) = field.0.split_at_mut(i);
-        for (j, left_item) in left.iter_mut().enumerate().take(i) {
-            std::mem::swap(&mut left_item[i], &mut right[0][j]);
-        }
-    }
+    let board_size = args.next()
+        .and_then(|arg| arg.parse().ok())
+        .filter(|&size: &usize| size > 0)
+        .unwrap_or(DEFAULT_BOARD_SIZE);
+
+    let win_len = args.next()
+        .and_then(|arg| arg.parse().ok())
+        .filter(|&len: &usize| len > 0 && len <= board_size)
+        .unwrap_or(DEFAULT_WIN_LEN.min(board_size));
 
-    field
+    (board_size, win_len)
 }
 
 /// Draws the text at the end of the game, when the game ends in a tie, win or lose.
 fn draw_end_text<'a>(
     text: impl Into<&'a str>,
     text_w_h: (u32, u32),
-    font: &'a Font,
-    texture_creator: &'a TextureCreator<WindowContext>,
     canvas: &mut WindowCanvas,
+    game_state: &mut GameState,
 ) -> Result<()> {
     let window_size = canvas.window().size();
     let text_width = text_w_h.0;
     let text_height = text_w_h.1;
 
-    let texture = get_text_texture(text, font, texture_creator).context("Creating texture for player Sign.")?;
+    let texture = get_text_texture(text.into(), TEXT_COLOR, &game_state.font, game_state.texture_creator, &mut game_state.texture_cache).context("Creating texture for ending text.")?;
 
     let text_x_pos = window_size.0 / 2 - text_width / 2;
     let text_y_pos = window_size.1 / 42;
     let target = Rect::new(text_x_pos as i32, text_y_pos as i32, text_width, text_height);
-    canvas.copy(&texture, None, Some(target)).expect("Displaying texture for ending text."); //TODO: Really do not want to use expect here
+    canvas.copy(texture, None, Some(target)).expect("Displaying texture for ending text."); //TODO: Really do not want to use expect here
 
     Ok(())
 }
@@ -350,13 +582,111 @@ fn on_mouse_clicked(x_pos: i32, y_pos: i32, game_state: &mut GameState) {
 
     for (row_idx, rows) in game_state.field_rects.iter().enumerate() {
         for (col_idx, rect) in rows.iter().enumerate() {
-            // Change Rect Sign and switch current player, if the Rect is clicked and it is empty
-            if rect.contains_point(clicked_point) && game_state.field.0[row_idx][col_idx].is_empty() {
-                game_state.field.0[row_idx][col_idx] = Cell(Some(game_state.current_player));
-                switch_player(&mut game_state.current_player);
+            if rect.contains_point(clicked_point) {
+                place_at(row_idx, col_idx, game_state);
+            }
+        }
+    }
+}
+
+/// Places the `current_player`'s [`Sign`] at `(row, col)` if that [`Cell`] is empty, switching
+/// turns and playing the click sound. Shared by mouse, keyboard and gamepad input.
+fn place_at(row: usize, col: usize, game_state: &mut GameState) {
+    if game_state.field.0[row][col].is_empty() {
+        game_state.field.0[row][col] = Cell(Some(game_state.current_player));
+        switch_player(&mut game_state.current_player);
+
+        if let Some(row_times) = game_state.placement_times.get_mut(row) {
+            if let Some(placed_at) = row_times.get_mut(col) {
+                *placed_at = Some(Instant::now());
+            }
+        }
+
+        #[cfg(feature = "audio")]
+        mixer::Channel::all().play(&game_state.sounds.click, 0).ok();
+
+        maybe_play_ai_turn(game_state);
+    }
+}
+
+/// If single-player mode is on and it's now the AI's turn, picks and plays its move via minimax.
+fn maybe_play_ai_turn(game_state: &mut GameState) {
+    if game_state.ai_sign != Some(game_state.current_player) {
+        return;
+    }
+
+    if check_win(&game_state.field, &!game_state.current_player, game_state.win_len) || check_draw(&game_state.field) {
+        return;
+    }
+
+    if let Some((row, col)) = best_move(&game_state.field, game_state.current_player, game_state.win_len) {
+        place_at(row, col, game_state);
+    }
+}
+
+/// # Returns
+///
+/// the empty cell that gives `ai_sign` the best minimax-scored outcome, or `None` if the
+/// `field` is full.
+fn best_move(field: &Field, ai_sign: Sign, win_len: usize) -> Option<(usize, usize)> {
+    let mut field = field.clone();
+    let mut best_score = i32::MIN;
+    let mut best_cell = None;
+
+    for row in 0..field.row_count() {
+        for col in 0..field.column_count() {
+            if !field.0[row][col].is_empty() {
+                continue;
+            }
+
+            field.0[row][col] = Cell(Some(ai_sign));
+            let score = minimax(&mut field, !ai_sign, ai_sign, win_len, 1);
+            field.0[row][col] = Cell(None);
+
+            if score > best_score {
+                best_score = score;
+                best_cell = Some((row, col));
+            }
+        }
+    }
+
+    best_cell
+}
+
+/// Scores `field` from `ai_sign`'s perspective assuming `to_move` plays next and both sides play
+/// optimally from here on: `+10 - depth` if the move that led here won for `ai_sign`, `depth - 10`
+/// if it won for the opponent, `0` on a full board, otherwise the max (AI's turn) or min
+/// (opponent's turn) over every empty cell's score. `depth` is the ply count, so faster wins and
+/// slower losses are preferred.
+fn minimax(field: &mut Field, to_move: Sign, ai_sign: Sign, win_len: usize, depth: i32) -> i32 {
+    if check_win(field, &!to_move, win_len) {
+        return if (!to_move) == ai_sign { 10 - depth } else { depth - 10 };
+    }
+    if check_draw(field) {
+        return 0;
+    }
+
+    let mut best_score = if to_move == ai_sign { i32::MIN } else { i32::MAX };
+
+    for row in 0..field.row_count() {
+        for col in 0..field.column_count() {
+            if !field.0[row][col].is_empty() {
+                continue;
             }
+
+            field.0[row][col] = Cell(Some(to_move));
+            let score = minimax(field, !to_move, ai_sign, win_len, depth + 1);
+            field.0[row][col] = Cell(None);
+
+            best_score = if to_move == ai_sign {
+                best_score.max(score)
+            } else {
+                best_score.min(score)
+            };
         }
     }
+
+    best_score
 }
 
 /// Switches the `current_player`.
@@ -364,16 +694,99 @@ fn switch_player(current_player: &mut Sign) {
     *current_player = !*current_player;
 }
 
-fn get_text_texture<'a>(
-    text: impl Into<&'a str>,
-    font: &'a Font<'a, 'a>,
+/// Handles a keyboard key press: arrow keys / WASD move the cursor, Enter/Space confirm a move
+/// at the cursor via [`place_at`].
+fn on_keyboard_input(keycode: Keycode, game_state: &mut GameState) {
+    match keycode {
+        Keycode::Up | Keycode::W => move_selected_cell(game_state, -1, 0),
+        Keycode::Down | Keycode::S => move_selected_cell(game_state, 1, 0),
+        Keycode::Left | Keycode::A => move_selected_cell(game_state, 0, -1),
+        Keycode::Right | Keycode::D => move_selected_cell(game_state, 0, 1),
+        Keycode::Return | Keycode::Space => place_at(game_state.selected_row, game_state.selected_col, game_state),
+        _ => {}
+    }
+}
+
+/// Handles a gamepad button press: D-pad moves the cursor, A confirms a move at the cursor via
+/// [`place_at`].
+fn on_controller_button_down(button: Button, game_state: &mut GameState) {
+    match button {
+        Button::DPadUp => move_selected_cell(game_state, -1, 0),
+        Button::DPadDown => move_selected_cell(game_state, 1, 0),
+        Button::DPadLeft => move_selected_cell(game_state, 0, -1),
+        Button::DPadRight => move_selected_cell(game_state, 0, 1),
+        Button::A => place_at(game_state.selected_row, game_state.selected_col, game_state),
+        _ => {}
+    }
+}
+
+/// Handles left-stick motion, edge-triggering a single cursor move per deadzone crossing so
+/// holding the stick over doesn't repeat every single axis event.
+fn on_controller_axis_motion(axis: Axis, value: i16, game_state: &mut GameState) {
+    let sign = axis_sign(value);
+
+    match axis {
+        Axis::LeftX => {
+            if sign != game_state.controller_stick_prev.0 {
+                game_state.controller_stick_prev.0 = sign;
+                if sign != 0 {
+                    move_selected_cell(game_state, 0, sign as isize);
+                }
+            }
+        }
+        Axis::LeftY => {
+            if sign != game_state.controller_stick_prev.1 {
+                game_state.controller_stick_prev.1 = sign;
+                if sign != 0 {
+                    move_selected_cell(game_state, sign as isize, 0);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// # Returns
+///
+/// `-1`/`0`/`1` depending on which side of [`CONTROLLER_AXIS_DEADZONE`] `value` falls on.
+fn axis_sign(value: i16) -> i8 {
+    if value > CONTROLLER_AXIS_DEADZONE {
+        1
+    } else if value < -CONTROLLER_AXIS_DEADZONE {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Moves the cursor by `(row_delta, col_delta)`, wrapping around the [`Field`]'s edges.
+fn move_selected_cell(game_state: &mut GameState, row_delta: isize, col_delta: isize) {
+    let row_count = game_state.field.row_count() as isize;
+    let col_count = game_state.field.column_count() as isize;
+
+    game_state.selected_row = (game_state.selected_row as isize + row_delta).rem_euclid(row_count) as usize;
+    game_state.selected_col = (game_state.selected_col as isize + col_delta).rem_euclid(col_count) as usize;
+}
+
+/// Looks up the rendered texture for `text` in `color` inside `cache`, rendering and inserting
+/// it on a cache miss. Hits avoid allocating a new SDL surface/texture every frame.
+fn get_text_texture<'a, 'b>(
+    text: &str,
+    color: Color,
+    font: &Font,
     texture_creator: &'a TextureCreator<WindowContext>,
-) -> Result<Texture<'a>> {
-    let surface = font
-        .render(text.into())
-        .blended(Color::RGB(0, 255, 0))?;
-    let texture = texture_creator
-        .create_texture_from_surface(&surface)?;
-
-    Ok(texture)
+    cache: &'b mut LruCache<(String, Color), Texture<'a>>,
+) -> Result<&'b Texture<'a>> {
+    let key = (text.to_owned(), color);
+
+    if !cache.contains(&key) {
+        let surface = font
+            .render(text)
+            .blended(color)?;
+        let texture = texture_creator
+            .create_texture_from_surface(&surface)?;
+        cache.put(key.clone(), texture);
+    }
+
+    Ok(cache.get(&key).expect("Texture was just inserted into the cache"))
 }